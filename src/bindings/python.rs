@@ -2,6 +2,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyList, PyDict};
 use numpy::{PyArray1, PyArray2};
 use crate::compute::survival::kaplan_meier::{KaplanMeier, KMResult};
+use crate::compute::survival::logrank;
 
 #[pyclass]
 struct PyKaplanMeier {
@@ -34,14 +35,43 @@ impl PyKaplanMeier {
         dict.set_item("std_error", result.std_error)?;
         dict.set_item("n_risk", result.n_risk)?;
         dict.set_item("n_event", result.n_event)?;
+        dict.set_item("ci_lower", result.ci_lower)?;
+        dict.set_item("ci_upper", result.ci_upper)?;
 
         Ok(dict.into())
     }
+
+    fn likelihood_ratio_interval(
+        &self,
+        time: Vec<f64>,
+        event: Vec<bool>,
+        index: usize,
+    ) -> PyResult<(f64, f64)> {
+        let result = self.inner.fit(&time, &event)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+        result.likelihood_ratio_interval(index)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))
+    }
+}
+
+#[pyfunction]
+fn logrank_test(py: Python, time: Vec<f64>, event: Vec<bool>, group: Vec<usize>) -> PyResult<PyObject> {
+    let result = logrank::logrank_test(&time, &event, &group)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("statistic", result.statistic)?;
+    dict.set_item("df", result.df)?;
+    dict.set_item("p_value", result.p_value)?;
+
+    Ok(dict.into())
 }
 
 pub fn register_survival_module(py: Python, parent_module: &PyModule) -> PyResult<()> {
     let m = PyModule::new(py, "survival")?;
     m.add_class::<PyKaplanMeier>()?;
+    m.add_function(wrap_pyfunction!(logrank_test, m)?)?;
     parent_module.add_submodule(m)?;
     Ok(())
-} 
\ No newline at end of file
+}
\ No newline at end of file
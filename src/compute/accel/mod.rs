@@ -0,0 +1,157 @@
+const NEAR_ZERO: f64 = 1e-12;
+
+/// Accelerates a scalar or vector fixed-point sequence with Aitken's
+/// delta-squared method, for iterative estimators (the Turnbull EM loop,
+/// future isotonic/ICM loops) that converge slowly under plain iteration.
+#[derive(Debug)]
+pub struct ConvergentSequence {
+    history: Vec<Vec<f64>>,
+}
+
+impl ConvergentSequence {
+    pub fn new() -> Self {
+        Self { history: Vec::new() }
+    }
+
+    /// Feeds the next raw iterate `x_{n}` into the sequence. Once three
+    /// iterates are available, returns the Aitken-accelerated estimate;
+    /// otherwise returns the raw iterate unchanged.
+    ///
+    /// Aitken's Δ² requires `x0`, `x1`, `x2` to be three *consecutive*
+    /// iterates of the same fixed-point map. Callers commonly restart
+    /// that map from the accelerated estimate (Steffensen-style), so the
+    /// history is cleared as soon as an extrapolation is emitted — this
+    /// keeps every triple confined to one raw sub-trajectory instead of
+    /// silently mixing pre- and post-extrapolation iterates, which would
+    /// invalidate the Δ² assumption.
+    pub fn push(&mut self, x: &[f64]) -> Vec<f64> {
+        self.history.push(x.to_vec());
+
+        if self.history.len() < 3 {
+            return x.to_vec();
+        }
+
+        let x0 = &self.history[0];
+        let x1 = &self.history[1];
+        let x2 = &self.history[2];
+
+        let accelerated = x0
+            .iter()
+            .zip(x1.iter())
+            .zip(x2.iter())
+            .map(|((&a, &b), &c)| aitken_delta_squared(a, b, c))
+            .collect();
+
+        self.history.clear();
+        accelerated
+    }
+
+    pub fn reset(&mut self) {
+        self.history.clear();
+    }
+}
+
+impl Default for ConvergentSequence {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `x_n - (Δx_n)² / Δ²x_n`, falling back to the latest raw iterate `x2`
+/// when `Δ²x_n` is too close to zero to divide by safely.
+fn aitken_delta_squared(x0: f64, x1: f64, x2: f64) -> f64 {
+    let dx0 = x1 - x0;
+    let d2x0 = x2 - 2.0 * x1 + x0;
+
+    if d2x0.abs() < NEAR_ZERO {
+        return x2;
+    }
+
+    x0 - dx0 * dx0 / d2x0
+}
+
+/// Repeatedly applies `update` to `initial`, running Aitken acceleration
+/// every third iteration, and stops once the change between successive
+/// (possibly accelerated) iterates falls below `tolerance` or
+/// `max_iterations` is reached. Returns the final iterate and the
+/// number of raw updates performed.
+pub fn accelerate<F>(initial: &[f64], tolerance: f64, max_iterations: usize, mut update: F) -> (Vec<f64>, usize)
+where
+    F: FnMut(&[f64]) -> Vec<f64>,
+{
+    let mut sequence = ConvergentSequence::new();
+    let mut current = initial.to_vec();
+
+    for iteration in 1..=max_iterations {
+        let raw_next = update(&current);
+        let accelerated = sequence.push(&raw_next);
+
+        let max_change = accelerated
+            .iter()
+            .zip(current.iter())
+            .fold(0.0_f64, |acc, (&new, &old)| acc.max((new - old).abs()));
+
+        current = accelerated;
+
+        if max_change < tolerance {
+            return (current, iteration);
+        }
+    }
+
+    (current, max_iterations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aitken_accelerates_linear_convergence() {
+        // x_n = limit - r^n converges geometrically to `limit`; Aitken
+        // should recover `limit` exactly from three iterates.
+        let limit = 2.0;
+        let r = 0.5;
+        let x0 = limit - r.powi(0);
+        let x1 = limit - r.powi(1);
+        let x2 = limit - r.powi(2);
+
+        let result = aitken_delta_squared(x0, x1, x2);
+        assert!((result - limit).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_aitken_falls_back_when_second_difference_is_tiny() {
+        let result = aitken_delta_squared(1.0, 1.0, 1.0);
+        assert_eq!(result, 1.0);
+    }
+
+    #[test]
+    fn test_accelerate_driver_converges() {
+        let (result, iterations) = accelerate(&[0.0], 1e-8, 1000, |x| vec![0.5 * x[0] + 1.0]);
+        assert!((result[0] - 2.0).abs() < 1e-6);
+        assert!(iterations < 1000);
+    }
+
+    #[test]
+    fn test_convergent_sequence_vector_elementwise() {
+        let mut seq = ConvergentSequence::new();
+        seq.push(&[0.0, 0.0]);
+        seq.push(&[1.0, 2.0]);
+        let accelerated = seq.push(&[1.5, 3.0]);
+        assert_eq!(accelerated.len(), 2);
+    }
+
+    #[test]
+    fn test_convergent_sequence_resets_history_after_extrapolation() {
+        // The fourth push must not be treated as the continuation of the
+        // first triple: after the third push emits an extrapolation, the
+        // history starts over, so this call should echo its raw input
+        // rather than blend it with the already-consumed iterates.
+        let mut seq = ConvergentSequence::new();
+        seq.push(&[0.0]);
+        seq.push(&[1.0]);
+        seq.push(&[1.5]);
+        let echoed = seq.push(&[10.0]);
+        assert_eq!(echoed, vec![10.0]);
+    }
+}
@@ -1,6 +1,14 @@
 use pyo3::prelude::*;
 use ndarray::Array1;
 
+pub mod simd;
+pub mod survival;
+pub mod parallel;
+pub mod isotonic;
+pub mod quantile;
+pub mod accel;
+pub mod simulation;
+
 /// Compute module for high-performance operations
 pub fn init_submodule(py: Python<'_>, parent_module: &PyModule) -> PyResult<()> {
     let submod = PyModule::new(py, "compute")?;
@@ -0,0 +1,47 @@
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+/// Configuration for a [`NumaAwareThreadPool`].
+#[derive(Debug, Clone)]
+pub struct ThreadPoolConfig {
+    pub num_threads: usize,
+}
+
+impl Default for ThreadPoolConfig {
+    fn default() -> Self {
+        Self {
+            num_threads: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+        }
+    }
+}
+
+/// A thread pool for the per-observation reductions used by the survival
+/// estimators and the simulation ensemble. Pinning to the available core count (rather
+/// than rayon's global pool) keeps these estimators from contending with
+/// other workloads running in the same process.
+#[derive(Debug)]
+pub struct NumaAwareThreadPool {
+    pool: ThreadPool,
+}
+
+impl NumaAwareThreadPool {
+    pub fn new(config: ThreadPoolConfig) -> Result<Self, crate::EpiRustError> {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(config.num_threads)
+            .build()
+            .map_err(|e| crate::EpiRustError::ComputeError(e.to_string()))?;
+
+        Ok(Self { pool })
+    }
+
+    /// Runs `f` over `data` on the pool, returning its result.
+    pub fn run<T, F, R>(&self, data: &[T], f: F) -> R
+    where
+        T: Sync,
+        F: FnOnce(&[T]) -> R + Send,
+        R: Send,
+    {
+        self.pool.install(|| f(data))
+    }
+}
@@ -0,0 +1,278 @@
+use polars::prelude::*;
+
+/// One tracked value in an [`EpsilonSummary`], bracketing its true rank
+/// between `rmin` and `rmax`.
+#[derive(Debug, Clone, Copy)]
+struct Tuple {
+    value: f64,
+    rmin: u64,
+    rmax: u64,
+}
+
+/// Streaming approximate-quantile summary implementing the Zhang-Wang
+/// fast algorithm: answers rank queries over an unbounded stream of
+/// `f64` values within a guaranteed relative error `epsilon`, without
+/// sorting or retaining the full dataset.
+#[derive(Debug)]
+pub struct EpsilonSummary {
+    epsilon: f64,
+    tuples: Vec<Tuple>,
+    n: u64,
+}
+
+impl EpsilonSummary {
+    pub fn new(epsilon: f64) -> Result<Self, crate::EpiRustError> {
+        if !(epsilon > 0.0 && epsilon < 1.0) {
+            return Err(crate::EpiRustError::ComputeError(
+                "epsilon must be in (0, 1)".into(),
+            ));
+        }
+
+        Ok(Self {
+            epsilon,
+            tuples: Vec::new(),
+            n: 0,
+        })
+    }
+
+    /// Ingests a single value, inserting it in rank order and updating
+    /// its neighbors' rank bounds.
+    pub fn insert(&mut self, value: f64) {
+        let pos = self
+            .tuples
+            .partition_point(|t| t.value < value);
+
+        let (rmin, rmax) = if self.tuples.is_empty() {
+            (1, 1)
+        } else if pos == 0 {
+            (1, self.tuples[0].rmax)
+        } else if pos == self.tuples.len() {
+            let prev = self.tuples[pos - 1];
+            (prev.rmin + 1, self.n + 1)
+        } else {
+            let prev = self.tuples[pos - 1];
+            let next = self.tuples[pos];
+            (prev.rmin + 1, next.rmax)
+        };
+
+        self.tuples.insert(pos, Tuple { value, rmin, rmax });
+        self.n += 1;
+
+        // Shift the bracket of everything after the insertion point:
+        // each later tuple's true rank grows by exactly one.
+        for t in self.tuples.iter_mut().skip(pos + 1) {
+            t.rmin += 1;
+            t.rmax += 1;
+        }
+
+        self.compress();
+    }
+
+    /// Merges `other` into `self` as a single hierarchical block-merge,
+    /// rather than replaying its values one at a time: each tuple keeps
+    /// its own rank bracket and gains the other summary's rank
+    /// contribution up to its value, and the two sorted tuple lists are
+    /// interleaved by value. Replaying representative values through
+    /// [`insert`](Self::insert) would drop the rank bookkeeping `other`
+    /// already built up (and undercount `n` by the number of stream
+    /// values `other` had compressed away), loosening the epsilon bound.
+    pub fn merge(&mut self, other: &EpsilonSummary) {
+        if other.tuples.is_empty() {
+            return;
+        }
+        if self.tuples.is_empty() {
+            self.tuples = other.tuples.clone();
+            self.n = other.n;
+            self.compress();
+            return;
+        }
+
+        let self_tuples = std::mem::take(&mut self.tuples);
+        let combined_n = self.n + other.n;
+
+        let mut merged: Vec<Tuple> = Vec::with_capacity(self_tuples.len() + other.tuples.len());
+        for t in &self_tuples {
+            let (lo, hi) = rank_contribution(&other.tuples, other.n, t.value);
+            merged.push(Tuple {
+                value: t.value,
+                rmin: t.rmin + lo,
+                rmax: t.rmax + hi,
+            });
+        }
+        for t in &other.tuples {
+            let (lo, hi) = rank_contribution(&self_tuples, self.n, t.value);
+            merged.push(Tuple {
+                value: t.value,
+                rmin: t.rmin + lo,
+                rmax: t.rmax + hi,
+            });
+        }
+        merged.sort_by(|a, b| a.value.partial_cmp(&b.value).unwrap());
+
+        self.tuples = merged;
+        self.n = combined_n;
+        self.compress();
+    }
+
+    /// Drops tuples whose rank band is already covered by
+    /// its neighbors, bounding summary size to `O((1/epsilon) log(epsilon * n))`.
+    fn compress(&mut self) {
+        if self.tuples.len() < 3 {
+            return;
+        }
+
+        let threshold = ((2.0 * self.epsilon * self.n as f64).floor()) as u64;
+        let mut kept: Vec<Tuple> = Vec::with_capacity(self.tuples.len());
+        kept.push(self.tuples[0]);
+
+        for i in 1..self.tuples.len() - 1 {
+            let prev = kept.last().unwrap();
+            let next = self.tuples[i + 1];
+            if next.rmax.saturating_sub(prev.rmin) <= threshold {
+                continue;
+            }
+            kept.push(self.tuples[i]);
+        }
+        kept.push(*self.tuples.last().unwrap());
+
+        self.tuples = kept;
+    }
+
+    /// Returns the value whose bracketed rank covers `ceil(phi * n)`,
+    /// for `phi` in `[0, 1]`; when compression has left no bracket
+    /// covering it, returns the value whose bracket lies nearest.
+    pub fn query(&self, phi: f64) -> Result<f64, crate::EpiRustError> {
+        if self.tuples.is_empty() {
+            return Err(crate::EpiRustError::ComputeError(
+                "cannot query an empty summary".into(),
+            ));
+        }
+        if !(0.0..=1.0).contains(&phi) {
+            return Err(crate::EpiRustError::ComputeError(
+                "phi must be in [0, 1]".into(),
+            ));
+        }
+
+        let target = (phi * self.n as f64).ceil() as u64;
+        let found = self
+            .tuples
+            .iter()
+            .min_by_key(|t| {
+                if target < t.rmin {
+                    t.rmin - target
+                } else {
+                    target.saturating_sub(t.rmax)
+                }
+            })
+            .unwrap();
+
+        Ok(found.value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tuples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tuples.is_empty()
+    }
+}
+
+/// Bounds the number of elements of `summary` (holding `total` stream
+/// values) that are at most `value`: `lo` is the tightest known lower
+/// bound, `hi` the tightest known upper bound, found by bracketing
+/// `value` between the summary's neighboring tuples.
+fn rank_contribution(summary: &[Tuple], total: u64, value: f64) -> (u64, u64) {
+    let idx = summary.partition_point(|t| t.value <= value);
+    if idx == 0 {
+        (0, summary.first().map(|t| t.rmax.saturating_sub(1)).unwrap_or(0))
+    } else if idx == summary.len() {
+        (total, total)
+    } else {
+        let prev = summary[idx - 1];
+        let next = summary[idx];
+        (prev.rmin, next.rmax.saturating_sub(1))
+    }
+}
+
+/// Feeds a Polars column through an [`EpsilonSummary`] in a single pass,
+/// so percentiles (medians, IQRs, survival-time percentiles) can be
+/// computed without materializing a full sort.
+pub fn summarize_column(df: &DataFrame, column: &str, epsilon: f64) -> Result<EpsilonSummary, crate::EpiRustError> {
+    let mut summary = EpsilonSummary::new(epsilon)?;
+
+    let series = df
+        .column(column)
+        .map_err(|e| crate::EpiRustError::DataError(e.to_string()))?;
+    let floats = series
+        .cast(&DataType::Float64)
+        .map_err(|e| crate::EpiRustError::DataError(e.to_string()))?;
+    let ca = floats
+        .f64()
+        .map_err(|e| crate::EpiRustError::DataError(e.to_string()))?;
+
+    for value in ca.into_iter().flatten() {
+        summary.insert(value);
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_on_sorted_stream_matches_exact_rank() {
+        let mut summary = EpsilonSummary::new(0.01).unwrap();
+        for v in 1..=100 {
+            summary.insert(v as f64);
+        }
+
+        let median = summary.query(0.5).unwrap();
+        assert!((median - 50.0).abs() <= 2.0);
+    }
+
+    #[test]
+    fn test_rejects_invalid_epsilon() {
+        assert!(EpsilonSummary::new(0.0).is_err());
+        assert!(EpsilonSummary::new(1.0).is_err());
+    }
+
+    #[test]
+    fn test_query_empty_errors() {
+        let summary = EpsilonSummary::new(0.1).unwrap();
+        assert!(summary.query(0.5).is_err());
+    }
+
+    #[test]
+    fn test_merge_combines_streams() {
+        let mut a = EpsilonSummary::new(0.05).unwrap();
+        let mut b = EpsilonSummary::new(0.05).unwrap();
+        for v in 1..=50 {
+            a.insert(v as f64);
+        }
+        for v in 51..=100 {
+            b.insert(v as f64);
+        }
+        a.merge(&b);
+
+        let median = a.query(0.5).unwrap();
+        assert!((median - 50.0).abs() <= 5.0);
+    }
+
+    #[test]
+    fn test_merge_preserves_total_count() {
+        let mut a = EpsilonSummary::new(0.05).unwrap();
+        let mut b = EpsilonSummary::new(0.05).unwrap();
+        for v in 1..=50 {
+            a.insert(v as f64);
+        }
+        for v in 51..=100 {
+            b.insert(v as f64);
+        }
+        a.merge(&b);
+
+        assert_eq!(a.n, 100);
+    }
+}
@@ -1,34 +1,51 @@
-use std::arch::x86_64::*;
 use crate::EpiRustError;
 use pyo3::prelude::*;
 
-#[derive(Debug)]
-pub struct SimdOperations {
-    capabilities: SimdCapabilities,
-}
+type SumKernel = fn(&[f64]) -> f64;
 
+/// Dispatches vectorized kernels to the best instruction set available on
+/// the running CPU, resolved once at construction time (the `get_imp()`
+/// pattern) rather than re-probing `is_x86_feature_detected!` on every
+/// call. Falls back to a portable scalar implementation on targets
+/// without a dedicated kernel (including non-x86_64/aarch64 targets such
+/// as wasm).
 #[derive(Debug)]
-struct SimdCapabilities {
-    sse2_available: bool,
-    avx2_available: bool,
-    avx512_available: bool,
+pub struct SimdOperations {
+    sum_kernel: SumKernel,
 }
 
-impl SimdCapabilities {
-    fn detect() -> Self {
+impl SimdOperations {
+    pub fn new() -> Self {
         Self {
-            sse2_available: is_x86_feature_detected!("sse2"),
-            avx2_available: is_x86_feature_detected!("avx2"),
-            avx512_available: is_x86_feature_detected!("avx512f"),
+            sum_kernel: Self::get_imp(),
         }
     }
-}
 
-impl SimdOperations {
-    pub fn new() -> Self {
-        Self {
-            capabilities: SimdCapabilities::detect(),
+    fn get_imp() -> SumKernel {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx512f") {
+                return x86::sum_avx512;
+            }
+            if is_x86_feature_detected!("avx2") {
+                return x86::sum_avx2;
+            }
+            if is_x86_feature_detected!("ssse3") {
+                return x86::sum_ssse3;
+            }
+            if is_x86_feature_detected!("sse2") {
+                return x86::sum_sse2;
+            }
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                return aarch64::sum_neon;
+            }
         }
+
+        sum_scalar
     }
 
     pub fn vector_sum(&self, data: &[f64]) -> Result<f64, EpiRustError> {
@@ -36,65 +53,73 @@ impl SimdOperations {
             return Ok(0.0);
         }
 
-        unsafe {
-            if self.capabilities.avx512_available {
-                Ok(self.sum_avx512(data))
-            } else if self.capabilities.avx2_available {
-                Ok(self.sum_avx2(data))
-            } else if self.capabilities.sse2_available {
-                Ok(self.sum_sse2(data))
-            } else {
-                Ok(self.sum_scalar(data))
-            }
-        }
+        Ok((self.sum_kernel)(data))
     }
 
-    #[target_feature(enable = "avx512f")]
-    unsafe fn sum_avx512(&self, data: &[f64]) -> f64 {
-        let mut sum = _mm512_setzero_pd();
-        let chunks = data.chunks_exact(8);
-        let remainder = chunks.remainder();
-
-        for chunk in chunks {
-            let v = _mm512_loadu_pd(chunk.as_ptr());
-            sum = _mm512_add_pd(sum, v);
+    // Optimized survival probability calculation. The recurrence is
+    // inherently sequential (each term depends on the previous
+    // cumulative product), so it runs scalar everywhere rather than
+    // dispatching a SIMD kernel.
+    pub fn compute_survival_probabilities(
+        &self,
+        n_risk: &[usize],
+        n_event: &[usize],
+    ) -> Result<Vec<f64>, EpiRustError> {
+        if n_risk.is_empty() || n_event.is_empty() {
+            return Ok(vec![1.0]);
         }
 
-        let mut result = _mm512_reduce_add_pd(sum);
-        
-        // Handle remaining elements
-        for &x in remainder {
-            result += x;
+        let mut survival = vec![1.0; n_risk.len() + 1];
+        let mut current_survival = 1.0;
+
+        for i in 0..n_risk.len() {
+            if n_risk[i] == 0 {
+                return Err(EpiRustError::ComputeError(
+                    "division by zero in survival probability calculation".into(),
+                ));
+            }
+
+            let prob = (n_risk[i] - n_event[i]) as f64 / n_risk[i] as f64;
+            current_survival *= prob;
+            survival[i + 1] = current_survival;
         }
-        
-        result
+
+        Ok(survival)
     }
+}
 
-    #[target_feature(enable = "avx2")]
-    unsafe fn sum_avx2(&self, data: &[f64]) -> f64 {
-        let mut sum = _mm256_setzero_pd();
-        let chunks = data.chunks_exact(4);
-        let remainder = chunks.remainder();
+impl Default for SimdOperations {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-        for chunk in chunks {
-            let v = _mm256_loadu_pd(chunk.as_ptr());
-            sum = _mm256_add_pd(sum, v);
-        }
+fn sum_scalar(data: &[f64]) -> f64 {
+    data.iter().sum()
+}
 
-        // Extract and sum the four doubles
-        let sum_array = std::mem::transmute::<__m256d, [f64; 4]>(sum);
-        let mut result = sum_array.iter().sum::<f64>();
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use std::arch::x86_64::*;
 
-        // Handle remaining elements
-        for &x in remainder {
-            result += x;
-        }
-        
-        result
+    pub fn sum_sse2(data: &[f64]) -> f64 {
+        unsafe { sum_sse2_impl(data) }
+    }
+
+    pub fn sum_ssse3(data: &[f64]) -> f64 {
+        unsafe { sum_ssse3_impl(data) }
+    }
+
+    pub fn sum_avx2(data: &[f64]) -> f64 {
+        unsafe { sum_avx2_impl(data) }
+    }
+
+    pub fn sum_avx512(data: &[f64]) -> f64 {
+        unsafe { sum_avx512_impl(data) }
     }
 
     #[target_feature(enable = "sse2")]
-    unsafe fn sum_sse2(&self, data: &[f64]) -> f64 {
+    unsafe fn sum_sse2_impl(data: &[f64]) -> f64 {
         let mut sum = _mm_setzero_pd();
         let chunks = data.chunks_exact(2);
         let remainder = chunks.remainder();
@@ -104,114 +129,76 @@ impl SimdOperations {
             sum = _mm_add_pd(sum, v);
         }
 
-        // Extract and sum the two doubles
         let sum_array = std::mem::transmute::<__m128d, [f64; 2]>(sum);
-        let mut result = sum_array.iter().sum::<f64>();
-
-        // Handle remaining elements
-        for &x in remainder {
-            result += x;
-        }
-        
+        let mut result: f64 = sum_array.iter().sum();
+        result += remainder.iter().sum::<f64>();
         result
     }
 
-    fn sum_scalar(&self, data: &[f64]) -> f64 {
-        data.iter().sum()
-    }
-
-    // Optimized survival probability calculation
-    pub fn compute_survival_probabilities(
-        &self,
-        n_risk: &[usize],
-        n_event: &[usize]
-    ) -> Result<Vec<f64>, EpiRustError> {
-        if n_risk.is_empty() || n_event.is_empty() {
-            return Ok(vec![1.0]);
-        }
-
-        unsafe {
-            if self.capabilities.avx2_available {
-                self.compute_survival_probabilities_avx2(n_risk, n_event)
-            } else if self.capabilities.sse2_available {
-                self.compute_survival_probabilities_sse2(n_risk, n_event)
-            } else {
-                self.compute_survival_probabilities_scalar(n_risk, n_event)
-            }
-        }
+    // SSSE3 doesn't add new floating-point add/load instructions over
+    // SSE2; this tier exists so CPUs that report SSSE3 but not AVX2
+    // get a kernel of their own name, which runs the SSE2 loop.
+    #[target_feature(enable = "ssse3")]
+    unsafe fn sum_ssse3_impl(data: &[f64]) -> f64 {
+        sum_sse2_impl(data)
     }
 
     #[target_feature(enable = "avx2")]
-    unsafe fn compute_survival_probabilities_avx2(
-        &self,
-        n_risk: &[usize],
-        n_event: &[usize]
-    ) -> Result<Vec<f64>, EpiRustError> {
-        let mut survival = vec![1.0; n_risk.len() + 1];
-        let mut current_survival = _mm256_set1_pd(1.0);
-
-        for i in 0..n_risk.len() {
-            if n_risk[i] == 0 {
-                return Err(EpiRustError::ComputeError(
-                    "division by zero in survival probability calculation".into()
-                ));
-            }
+    unsafe fn sum_avx2_impl(data: &[f64]) -> f64 {
+        let mut sum = _mm256_setzero_pd();
+        let chunks = data.chunks_exact(4);
+        let remainder = chunks.remainder();
 
-            let prob = (n_risk[i] - n_event[i]) as f64 / n_risk[i] as f64;
-            let prob_vec = _mm256_set1_pd(prob);
-            current_survival = _mm256_mul_pd(current_survival, prob_vec);
-            survival[i + 1] = _mm256_cvtsd_f64(current_survival);
+        for chunk in chunks {
+            let v = _mm256_loadu_pd(chunk.as_ptr());
+            sum = _mm256_add_pd(sum, v);
         }
 
-        Ok(survival)
+        let sum_array = std::mem::transmute::<__m256d, [f64; 4]>(sum);
+        let mut result: f64 = sum_array.iter().sum();
+        result += remainder.iter().sum::<f64>();
+        result
     }
 
-    #[target_feature(enable = "sse2")]
-    unsafe fn compute_survival_probabilities_sse2(
-        &self,
-        n_risk: &[usize],
-        n_event: &[usize]
-    ) -> Result<Vec<f64>, EpiRustError> {
-        let mut survival = vec![1.0; n_risk.len() + 1];
-        let mut current_survival = _mm_set1_pd(1.0);
-
-        for i in 0..n_risk.len() {
-            if n_risk[i] == 0 {
-                return Err(EpiRustError::ComputeError(
-                    "division by zero in survival probability calculation".into()
-                ));
-            }
+    #[target_feature(enable = "avx512f")]
+    unsafe fn sum_avx512_impl(data: &[f64]) -> f64 {
+        let mut sum = _mm512_setzero_pd();
+        let chunks = data.chunks_exact(8);
+        let remainder = chunks.remainder();
 
-            let prob = (n_risk[i] - n_event[i]) as f64 / n_risk[i] as f64;
-            let prob_vec = _mm_set1_pd(prob);
-            current_survival = _mm_mul_pd(current_survival, prob_vec);
-            survival[i + 1] = _mm_cvtsd_f64(current_survival);
+        for chunk in chunks {
+            let v = _mm512_loadu_pd(chunk.as_ptr());
+            sum = _mm512_add_pd(sum, v);
         }
 
-        Ok(survival)
+        let mut result = _mm512_reduce_add_pd(sum);
+        result += remainder.iter().sum::<f64>();
+        result
     }
+}
 
-    fn compute_survival_probabilities_scalar(
-        &self,
-        n_risk: &[usize],
-        n_event: &[usize]
-    ) -> Result<Vec<f64>, EpiRustError> {
-        let mut survival = vec![1.0; n_risk.len() + 1];
-        let mut current_survival = 1.0;
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use std::arch::aarch64::*;
 
-        for i in 0..n_risk.len() {
-            if n_risk[i] == 0 {
-                return Err(EpiRustError::ComputeError(
-                    "division by zero in survival probability calculation".into()
-                ));
-            }
+    pub fn sum_neon(data: &[f64]) -> f64 {
+        unsafe { sum_neon_impl(data) }
+    }
 
-            let prob = (n_risk[i] - n_event[i]) as f64 / n_risk[i] as f64;
-            current_survival *= prob;
-            survival[i + 1] = current_survival;
+    #[target_feature(enable = "neon")]
+    unsafe fn sum_neon_impl(data: &[f64]) -> f64 {
+        let mut sum = vdupq_n_f64(0.0);
+        let chunks = data.chunks_exact(2);
+        let remainder = chunks.remainder();
+
+        for chunk in chunks {
+            let v = vld1q_f64(chunk.as_ptr());
+            sum = vaddq_f64(sum, v);
         }
 
-        Ok(survival)
+        let mut result = vgetq_lane_f64(sum, 0) + vgetq_lane_f64(sum, 1);
+        result += remainder.iter().sum::<f64>();
+        result
     }
 }
 
@@ -234,20 +221,24 @@ mod tests {
         assert_eq!(sum, 15.0);
     }
 
+    #[test]
+    fn test_vector_sum_empty() {
+        let ops = SimdOperations::new();
+        assert_eq!(ops.vector_sum(&[]).unwrap(), 0.0);
+    }
+
     #[test]
     fn test_survival_probabilities() {
         let ops = SimdOperations::new();
         let n_risk = vec![100, 90, 80, 70];
         let n_event = vec![10, 5, 8, 7];
-        
+
         let survival = ops.compute_survival_probabilities(&n_risk, &n_event).unwrap();
-        
-        // Check that probabilities are decreasing
+
         for i in 1..survival.len() {
-            assert!(survival[i] <= survival[i-1]);
+            assert!(survival[i] <= survival[i - 1]);
         }
-        
-        // Check bounds
+
         assert!(survival.iter().all(|&x| x >= 0.0 && x <= 1.0));
     }
 
@@ -256,11 +247,11 @@ mod tests {
         let ops = SimdOperations::new();
         let mut rng = rand::thread_rng();
         let size = 1000;
-        
+
         let n_risk: Vec<usize> = (1..=size).map(|x| size - x + 1).collect();
         let n_event: Vec<usize> = (0..size).map(|_| rng.gen_range(0..10)).collect();
-        
+
         let survival = ops.compute_survival_probabilities(&n_risk, &n_event).unwrap();
         assert_eq!(survival.len(), size + 1);
     }
-} 
\ No newline at end of file
+}
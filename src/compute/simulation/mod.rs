@@ -0,0 +1,368 @@
+use crate::compute::parallel::NumaAwareThreadPool;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rand_distr::{Binomial, Distribution, Gamma, Poisson};
+use rayon::prelude::*;
+
+/// Parameters for a stochastic SIR trajectory.
+#[derive(Debug, Clone)]
+pub struct SirParams {
+    pub population: usize,
+    pub initial_infected: usize,
+    pub beta: f64,
+    pub recovery_rate: f64,
+    /// Expected number of imported cases per step (`Poisson(rate)`),
+    /// on top of locally transmitted infections.
+    pub importation_rate: Option<f64>,
+    pub time_steps: usize,
+}
+
+/// Parameters for a stochastic SEIR trajectory, where the latent and
+/// infectious durations are themselves drawn from `Gamma(shape, scale)`
+/// each step rather than fixed, reflecting population heterogeneity in
+/// how long individuals spend in each compartment.
+#[derive(Debug, Clone)]
+pub struct SeirParams {
+    pub population: usize,
+    pub initial_exposed: usize,
+    pub beta: f64,
+    pub latent_duration_shape: f64,
+    pub latent_duration_scale: f64,
+    pub infectious_duration_shape: f64,
+    pub infectious_duration_scale: f64,
+    pub importation_rate: Option<f64>,
+    pub time_steps: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct SirTrajectory {
+    pub susceptible: Vec<usize>,
+    pub infected: Vec<usize>,
+    pub recovered: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SeirTrajectory {
+    pub susceptible: Vec<usize>,
+    pub exposed: Vec<usize>,
+    pub infected: Vec<usize>,
+    pub recovered: Vec<usize>,
+}
+
+/// Median and inter-quantile envelope for an ensemble summary statistic.
+#[derive(Debug, Clone)]
+pub struct Band {
+    pub median: f64,
+    pub lower_quartile: f64,
+    pub upper_quartile: f64,
+}
+
+/// Outbreak-size and peak-timing summary bands across an ensemble of
+/// trajectories.
+#[derive(Debug, Clone)]
+pub struct EnsembleSummary {
+    pub outbreak_size: Band,
+    pub peak_time: Band,
+}
+
+/// Runs a single stochastic SIR trajectory with a seeded RNG for
+/// reproducibility. At each step, new infections are drawn as
+/// `Binomial(S, 1 - exp(-beta*I/N))` and recoveries as `Binomial(I, 1 -
+/// exp(-recovery_rate))`, with optional Poisson-distributed imported
+/// cases.
+pub fn simulate_sir(params: &SirParams, seed: u64) -> Result<SirTrajectory, crate::EpiRustError> {
+    if params.population == 0 {
+        return Err(crate::EpiRustError::ComputeError(
+            "population must be greater than zero".into(),
+        ));
+    }
+    if params.initial_infected > params.population {
+        return Err(crate::EpiRustError::ComputeError(
+            "initial_infected cannot exceed population".into(),
+        ));
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let n = params.population as f64;
+
+    let mut s = params.population - params.initial_infected;
+    let mut i = params.initial_infected;
+    let mut r = 0usize;
+
+    let mut susceptible = vec![s];
+    let mut infected = vec![i];
+    let mut recovered = vec![r];
+
+    for _ in 0..params.time_steps {
+        let infection_prob = (1.0 - (-params.beta * i as f64 / n).exp()).clamp(0.0, 1.0);
+        let mut new_infections = sample_binomial(&mut rng, s as u64, infection_prob)? as usize;
+
+        if let Some(rate) = params.importation_rate {
+            let imported = Poisson::new(rate)
+                .map_err(|e| crate::EpiRustError::ComputeError(e.to_string()))?
+                .sample(&mut rng) as usize;
+            new_infections = (new_infections + imported).min(s);
+        }
+
+        let recovery_prob = (1.0 - (-params.recovery_rate).exp()).clamp(0.0, 1.0);
+        let recoveries = sample_binomial(&mut rng, i as u64, recovery_prob)? as usize;
+
+        s -= new_infections;
+        i = i + new_infections - recoveries;
+        r += recoveries;
+
+        susceptible.push(s);
+        infected.push(i);
+        recovered.push(r);
+    }
+
+    Ok(SirTrajectory {
+        susceptible,
+        infected,
+        recovered,
+    })
+}
+
+/// Runs a single stochastic SEIR trajectory, analogous to
+/// [`simulate_sir`] but with an exposed (latent, non-infectious)
+/// compartment; each step's transition rates are the reciprocals of
+/// latent and infectious durations drawn afresh from Gamma distributions.
+pub fn simulate_seir(params: &SeirParams, seed: u64) -> Result<SeirTrajectory, crate::EpiRustError> {
+    if params.population == 0 {
+        return Err(crate::EpiRustError::ComputeError(
+            "population must be greater than zero".into(),
+        ));
+    }
+    if params.initial_exposed > params.population {
+        return Err(crate::EpiRustError::ComputeError(
+            "initial_exposed cannot exceed population".into(),
+        ));
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let n = params.population as f64;
+
+    let latent_duration = Gamma::new(params.latent_duration_shape, params.latent_duration_scale)
+        .map_err(|e| crate::EpiRustError::ComputeError(e.to_string()))?;
+    let infectious_duration = Gamma::new(
+        params.infectious_duration_shape,
+        params.infectious_duration_scale,
+    )
+    .map_err(|e| crate::EpiRustError::ComputeError(e.to_string()))?;
+
+    let mut s = params.population - params.initial_exposed;
+    let mut e = params.initial_exposed;
+    let mut i = 0usize;
+    let mut r = 0usize;
+
+    let mut susceptible = vec![s];
+    let mut exposed = vec![e];
+    let mut infected = vec![i];
+    let mut recovered = vec![r];
+
+    for _ in 0..params.time_steps {
+        let infection_prob = (1.0 - (-params.beta * i as f64 / n).exp()).clamp(0.0, 1.0);
+        let mut new_exposed = sample_binomial(&mut rng, s as u64, infection_prob)? as usize;
+
+        if let Some(rate) = params.importation_rate {
+            let imported = Poisson::new(rate)
+                .map_err(|err| crate::EpiRustError::ComputeError(err.to_string()))?
+                .sample(&mut rng) as usize;
+            new_exposed = (new_exposed + imported).min(s);
+        }
+
+        let latent_rate = 1.0 / latent_duration.sample(&mut rng).max(1e-6);
+        let progression_prob = (1.0 - (-latent_rate).exp()).clamp(0.0, 1.0);
+        let new_infectious = sample_binomial(&mut rng, e as u64, progression_prob)? as usize;
+
+        let infectious_rate = 1.0 / infectious_duration.sample(&mut rng).max(1e-6);
+        let recovery_prob = (1.0 - (-infectious_rate).exp()).clamp(0.0, 1.0);
+        let recoveries = sample_binomial(&mut rng, i as u64, recovery_prob)? as usize;
+
+        s -= new_exposed;
+        e = e + new_exposed - new_infectious;
+        i = i + new_infectious - recoveries;
+        r += recoveries;
+
+        susceptible.push(s);
+        exposed.push(e);
+        infected.push(i);
+        recovered.push(r);
+    }
+
+    Ok(SeirTrajectory {
+        susceptible,
+        exposed,
+        infected,
+        recovered,
+    })
+}
+
+/// Runs an ensemble of independent SIR trajectories concurrently,
+/// returning each trajectory alongside summary bands for outbreak size
+/// and peak timing.
+pub fn simulate_sir_ensemble(
+    params: &SirParams,
+    n_trajectories: usize,
+    base_seed: u64,
+) -> Result<(Vec<SirTrajectory>, EnsembleSummary), crate::EpiRustError> {
+    let thread_pool = NumaAwareThreadPool::new(Default::default())?;
+    let seeds: Vec<u64> = (0..n_trajectories as u64).map(|i| base_seed + i).collect();
+
+    let trajectories: Vec<SirTrajectory> = thread_pool
+        .run(&seeds, |seeds| {
+            seeds
+                .par_iter()
+                .map(|&seed| simulate_sir(params, seed))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+
+    let outbreak_sizes: Vec<f64> = trajectories
+        .iter()
+        .map(|t| *t.recovered.last().unwrap_or(&0) as f64)
+        .collect();
+    let peak_times: Vec<f64> = trajectories
+        .iter()
+        .map(|t| argmax(&t.infected) as f64)
+        .collect();
+
+    let summary = EnsembleSummary {
+        outbreak_size: band(&outbreak_sizes),
+        peak_time: band(&peak_times),
+    };
+
+    Ok((trajectories, summary))
+}
+
+fn sample_binomial(rng: &mut StdRng, n: u64, p: f64) -> Result<u64, crate::EpiRustError> {
+    if n == 0 {
+        return Ok(0);
+    }
+    let dist = Binomial::new(n, p).map_err(|e| crate::EpiRustError::ComputeError(e.to_string()))?;
+    Ok(dist.sample(rng))
+}
+
+fn argmax(values: &[usize]) -> usize {
+    values
+        .iter()
+        .enumerate()
+        .max_by_key(|&(_, v)| *v)
+        .map(|(idx, _)| idx)
+        .unwrap_or(0)
+}
+
+/// Computes the median and inter-quartile envelope of `values`.
+fn band(values: &[f64]) -> Band {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    Band {
+        median: percentile(&sorted, 0.5),
+        lower_quartile: percentile(&sorted, 0.25),
+        upper_quartile: percentile(&sorted, 0.75),
+    }
+}
+
+fn percentile(sorted: &[f64], phi: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let idx = ((phi * (sorted.len() - 1) as f64).round()) as usize;
+    sorted[idx.min(sorted.len() - 1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sir_conserves_population() {
+        let params = SirParams {
+            population: 1000,
+            initial_infected: 10,
+            beta: 0.3,
+            recovery_rate: 0.1,
+            importation_rate: None,
+            time_steps: 50,
+        };
+
+        let trajectory = simulate_sir(&params, 42).unwrap();
+        for i in 0..trajectory.susceptible.len() {
+            assert_eq!(
+                trajectory.susceptible[i] + trajectory.infected[i] + trajectory.recovered[i],
+                1000
+            );
+        }
+    }
+
+    #[test]
+    fn test_sir_is_reproducible_given_same_seed() {
+        let params = SirParams {
+            population: 500,
+            initial_infected: 5,
+            beta: 0.4,
+            recovery_rate: 0.2,
+            importation_rate: Some(0.5),
+            time_steps: 20,
+        };
+
+        let a = simulate_sir(&params, 7).unwrap();
+        let b = simulate_sir(&params, 7).unwrap();
+        assert_eq!(a.infected, b.infected);
+    }
+
+    #[test]
+    fn test_seir_conserves_population() {
+        let params = SeirParams {
+            population: 800,
+            initial_exposed: 20,
+            beta: 0.35,
+            latent_duration_shape: 2.0,
+            latent_duration_scale: 1.5,
+            infectious_duration_shape: 2.0,
+            infectious_duration_scale: 2.0,
+            importation_rate: None,
+            time_steps: 30,
+        };
+
+        let trajectory = simulate_seir(&params, 11).unwrap();
+        for i in 0..trajectory.susceptible.len() {
+            let total = trajectory.susceptible[i]
+                + trajectory.exposed[i]
+                + trajectory.infected[i]
+                + trajectory.recovered[i];
+            assert_eq!(total, 800);
+        }
+    }
+
+    #[test]
+    fn test_ensemble_produces_bands_for_every_trajectory() {
+        let params = SirParams {
+            population: 200,
+            initial_infected: 5,
+            beta: 0.5,
+            recovery_rate: 0.15,
+            importation_rate: None,
+            time_steps: 25,
+        };
+
+        let (trajectories, summary) = simulate_sir_ensemble(&params, 20, 0).unwrap();
+        assert_eq!(trajectories.len(), 20);
+        assert!(summary.outbreak_size.median >= 0.0);
+        assert!(summary.outbreak_size.lower_quartile <= summary.outbreak_size.upper_quartile);
+    }
+
+    #[test]
+    fn test_rejects_initial_infected_over_population() {
+        let params = SirParams {
+            population: 10,
+            initial_infected: 20,
+            beta: 0.3,
+            recovery_rate: 0.1,
+            importation_rate: None,
+            time_steps: 5,
+        };
+
+        assert!(simulate_sir(&params, 1).is_err());
+    }
+}
@@ -1,8 +1,13 @@
 use crate::compute::simd::SimdOperations;
 use crate::compute::parallel::NumaAwareThreadPool;
 use ndarray::{Array1, Array2};
+use statrs::distribution::{ContinuousCDF, Normal};
 use std::arch::x86_64::*;
 
+const DEFAULT_CONFIDENCE: f64 = 0.95;
+/// Chi-square 1-df 95% cutoff used by [`KMResult::likelihood_ratio_interval`].
+const CHI_SQUARE_1DF_95: f64 = 3.8415;
+
 #[derive(Debug)]
 pub struct KaplanMeier {
     simd_ops: SimdOperations,
@@ -16,6 +21,147 @@ pub struct KMResult {
     pub std_error: Vec<f64>,
     pub n_risk: Vec<usize>,
     pub n_event: Vec<usize>,
+    /// Pointwise lower confidence bound, computed on the
+    /// complementary log-log scale at [`DEFAULT_CONFIDENCE`].
+    pub ci_lower: Vec<f64>,
+    /// Pointwise upper confidence bound, computed on the
+    /// complementary log-log scale at [`DEFAULT_CONFIDENCE`].
+    pub ci_upper: Vec<f64>,
+}
+
+impl KMResult {
+    /// Recomputes pointwise confidence bands at an arbitrary `confidence`
+    /// level (e.g. `0.90`, `0.99`), using the complementary log-log
+    /// transform so bounds stay within `[0, 1]`: `S(t)^exp(±z *
+    /// sqrt(v) / log(S(t)))`, where `v = (std_error / S(t))^2` is the
+    /// cumulative Greenwood sum.
+    pub fn confidence_bands(&self, confidence: f64) -> Result<(Vec<f64>, Vec<f64>), crate::EpiRustError> {
+        let z = confidence_z(confidence)?;
+
+        let mut lower = vec![0.0; self.survival.len()];
+        let mut upper = vec![0.0; self.survival.len()];
+
+        for i in 0..self.survival.len() {
+            let s = self.survival[i];
+            if !(0.0 < s && s < 1.0) {
+                lower[i] = s;
+                upper[i] = s;
+                continue;
+            }
+
+            let v = (self.std_error[i] / s).powi(2);
+            let theta = z * v.sqrt() / s.ln();
+            let a = s.powf(theta.exp());
+            let b = s.powf((-theta).exp());
+
+            lower[i] = a.min(b);
+            upper[i] = a.max(b);
+        }
+
+        Ok((lower, upper))
+    }
+
+    /// Profiles the survival estimate at `self.time[index]` by maximizing
+    /// the multinomial log-likelihood subject to `S(t) = c`, using the
+    /// Thomas-Grunkemeier Lagrange-multiplier construction: holding a
+    /// scalar `theta`, the constrained per-risk-set hazard is `q_i(theta)
+    /// = d_i / (n_i - theta)`, and `theta = 0` recovers the unconstrained
+    /// MLE. Returns the set of `c` where `2 * (loglik_max - loglik(c)) <=
+    /// 3.8415` (the chi-square 1-df 95% cutoff).
+    pub fn likelihood_ratio_interval(&self, index: usize) -> Result<(f64, f64), crate::EpiRustError> {
+        if index >= self.n_risk.len() {
+            return Err(crate::EpiRustError::ComputeError(
+                "time index out of range for likelihood ratio interval".into(),
+            ));
+        }
+
+        let n_risk = &self.n_risk[..=index];
+        let n_event = &self.n_event[..=index];
+
+        let (_, loglik_mle) = profile_loglik(n_risk, n_event, 0.0).ok_or_else(|| {
+            crate::EpiRustError::ComputeError("failed to evaluate MLE likelihood".into())
+        })?;
+
+        let bound = |step_sign: f64| -> f64 {
+            let mut lo = 0.0;
+            let mut hi = step_sign;
+
+            loop {
+                match profile_loglik(n_risk, n_event, hi) {
+                    Some((_, ll)) if 2.0 * (loglik_mle - ll) <= CHI_SQUARE_1DF_95 => {
+                        lo = hi;
+                        hi *= 2.0;
+                        if hi.abs() > 1e9 {
+                            break;
+                        }
+                    }
+                    _ => break,
+                }
+            }
+
+            let (mut lo_b, mut hi_b) = (lo, hi);
+            for _ in 0..100 {
+                let mid = (lo_b + hi_b) / 2.0;
+                match profile_loglik(n_risk, n_event, mid) {
+                    Some((_, ll)) if 2.0 * (loglik_mle - ll) <= CHI_SQUARE_1DF_95 => lo_b = mid,
+                    _ => hi_b = mid,
+                }
+            }
+
+            profile_loglik(n_risk, n_event, (lo_b + hi_b) / 2.0)
+                .map(|(c, _)| c)
+                .unwrap_or(self.survival[index])
+        };
+
+        let c_a = bound(1.0);
+        let c_b = bound(-1.0);
+
+        Ok((c_a.min(c_b), c_a.max(c_b)))
+    }
+}
+
+/// Evaluates `(c, loglik)` for the constrained hazards `q_i(theta) = d_i
+/// / (n_i - theta)`, where `c = prod_i (1 - q_i)`. Returns `None` when
+/// `theta` pushes a hazard outside `[0, 1)`.
+fn profile_loglik(n_risk: &[usize], n_event: &[usize], theta: f64) -> Option<(f64, f64)> {
+    let mut c = 1.0;
+    let mut loglik = 0.0;
+
+    for (&n_i, &d_i) in n_risk.iter().zip(n_event.iter()) {
+        let n = n_i as f64;
+        let d = d_i as f64;
+        let denom = n - theta;
+
+        if denom <= d || denom <= 0.0 {
+            return None;
+        }
+
+        let q = d / denom;
+        c *= 1.0 - q;
+
+        if d > 0.0 {
+            loglik += d * q.ln();
+        }
+        if n - d > 0.0 {
+            loglik += (n - d) * (1.0 - q).ln();
+        }
+    }
+
+    Some((c, loglik))
+}
+
+/// Converts a two-sided confidence level (e.g. `0.95`) to the
+/// corresponding standard normal critical value.
+fn confidence_z(confidence: f64) -> Result<f64, crate::EpiRustError> {
+    if !(0.0 < confidence && confidence < 1.0) {
+        return Err(crate::EpiRustError::ComputeError(
+            "confidence level must be in (0, 1)".into(),
+        ));
+    }
+
+    let normal = Normal::new(0.0, 1.0)
+        .map_err(|e| crate::EpiRustError::ComputeError(e.to_string()))?;
+    Ok(normal.inverse_cdf(0.5 + confidence / 2.0))
 }
 
 impl KaplanMeier {
@@ -51,13 +197,21 @@ impl KaplanMeier {
         // Compute standard errors in parallel
         let std_error = self.compute_standard_errors(&survival, &n_risk, &n_event)?;
 
-        Ok(KMResult {
+        let mut result = KMResult {
             time: unique_times,
             survival,
             std_error,
             n_risk,
             n_event,
-        })
+            ci_lower: Vec::new(),
+            ci_upper: Vec::new(),
+        };
+
+        let (ci_lower, ci_upper) = result.confidence_bands(DEFAULT_CONFIDENCE)?;
+        result.ci_lower = ci_lower;
+        result.ci_upper = ci_upper;
+
+        Ok(result)
     }
 
     #[target_feature(enable = "avx2")]
@@ -129,18 +283,20 @@ impl KaplanMeier {
         n_risk: &[usize],
         n_event: &[usize]
     ) -> Result<Vec<f64>, crate::EpiRustError> {
-        // Greenwood's formula
+        // Greenwood's formula: Var(S(t)) = S(t)^2 * sum_{t_i<=t} d_i /
+        // (n_i * (n_i - d_i)), so each std_error needs the *cumulative*
+        // sum of per-risk-set terms up to and including that index, not
+        // just the term at that index.
         let mut std_error = vec![0.0; survival.len()];
-        
+
         self.thread_pool.run(&survival, |_| {
+            let mut cumulative = 0.0;
             for i in 0..survival.len() {
-                if n_risk[i] == 0 {
-                    continue;
+                if n_risk[i] != 0 && n_risk[i] > n_event[i] {
+                    cumulative += n_event[i] as f64
+                        / ((n_risk[i] * (n_risk[i] - n_event[i])) as f64);
                 }
-                
-                let variance = n_event[i] as f64 / 
-                    ((n_risk[i] * (n_risk[i] - n_event[i])) as f64);
-                std_error[i] = (survival[i] * variance.sqrt()).abs();
+                std_error[i] = (survival[i] * cumulative.sqrt()).abs();
             }
         });
 
@@ -177,8 +333,86 @@ mod tests {
         let km = KaplanMeier::new().unwrap();
         let time = vec![1.0, 2.0, 3.0];
         let event = vec![false, false, false];
-        
+
         let result = km.fit(&time, &event).unwrap();
         assert!(result.survival.iter().all(|&s| (s - 1.0).abs() < 1e-10));
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_confidence_bands_stay_within_unit_interval() {
+        let km = KaplanMeier::new().unwrap();
+        let time = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let event = vec![true, true, false, true, false];
+
+        let result = km.fit(&time, &event).unwrap();
+
+        for i in 0..result.survival.len() {
+            assert!(result.ci_lower[i] >= 0.0 && result.ci_lower[i] <= 1.0);
+            assert!(result.ci_upper[i] >= 0.0 && result.ci_upper[i] <= 1.0);
+            assert!(result.ci_lower[i] <= result.survival[i] + 1e-9);
+            assert!(result.ci_upper[i] >= result.survival[i] - 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_standard_errors_use_cumulative_greenwood_variance() {
+        // Greenwood's formula accumulates d_i / (n_i * (n_i - d_i)) over
+        // every risk-set change up to and including each time point, not
+        // just the term at that point. Re-derive the cumulative sum from
+        // the fitted n_risk/n_event and check std_error matches it at
+        // every index, including ones past the first risk-set change.
+        let km = KaplanMeier::new().unwrap();
+        let time = vec![1.0, 1.0, 2.0, 3.0, 3.0, 4.0];
+        let event = vec![true, false, true, true, false, true];
+
+        let result = km.fit(&time, &event).unwrap();
+
+        let mut cumulative = 0.0;
+        for i in 0..result.survival.len() {
+            let n = result.n_risk[i] as f64;
+            let d = result.n_event[i] as f64;
+            if result.n_risk[i] > result.n_event[i] {
+                cumulative += d / (n * (n - d));
+            }
+            let expected = (result.survival[i] * cumulative.sqrt()).abs();
+            assert!((result.std_error[i] - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_likelihood_ratio_interval_brackets_estimate() {
+        let km = KaplanMeier::new().unwrap();
+        let time = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let event = vec![true, true, false, true, true, false, true, false];
+
+        let result = km.fit(&time, &event).unwrap();
+        let last = result.survival.len() - 1;
+        let (lower, upper) = result.likelihood_ratio_interval(last).unwrap();
+
+        assert!(lower <= result.survival[last] + 1e-6);
+        assert!(upper >= result.survival[last] - 1e-6);
+        assert!((0.0..=1.0).contains(&lower));
+        assert!((0.0..=1.0).contains(&upper));
+    }
+
+    #[test]
+    fn test_likelihood_ratio_interval_rejects_out_of_range_index() {
+        let km = KaplanMeier::new().unwrap();
+        let time = vec![1.0, 2.0, 3.0];
+        let event = vec![true, true, false];
+        let result = km.fit(&time, &event).unwrap();
+
+        assert!(result.likelihood_ratio_interval(100).is_err());
+    }
+
+    #[test]
+    fn test_confidence_bands_reject_invalid_level() {
+        let km = KaplanMeier::new().unwrap();
+        let time = vec![1.0, 2.0, 3.0];
+        let event = vec![true, true, false];
+        let result = km.fit(&time, &event).unwrap();
+
+        assert!(result.confidence_bands(0.0).is_err());
+        assert!(result.confidence_bands(1.0).is_err());
+    }
+}
\ No newline at end of file
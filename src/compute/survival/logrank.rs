@@ -0,0 +1,280 @@
+use crate::compute::parallel::NumaAwareThreadPool;
+use rayon::prelude::*;
+
+/// Result of a [`logrank_test`] comparing survival between two or more
+/// groups.
+#[derive(Debug)]
+pub struct LogRankResult {
+    pub statistic: f64,
+    pub df: usize,
+    pub p_value: f64,
+}
+
+/// Compares survival between two or more groups with the log-rank test.
+///
+/// At each distinct event time, the observed events per group are
+/// compared against the expected count under the null of equal hazards
+/// (`expected_gk = n_risk_gk * total_events_k / total_risk_k`), weighted
+/// by the hypergeometric variance. The accumulated `O_g - E_g` vector and
+/// its variance-covariance matrix form a quadratic statistic that is
+/// chi-square distributed with `groups - 1` degrees of freedom.
+pub fn logrank_test(time: &[f64], event: &[bool], group: &[usize]) -> Result<LogRankResult, crate::EpiRustError> {
+    if time.len() != event.len() || time.len() != group.len() {
+        return Err(crate::EpiRustError::ComputeError(
+            "time, event and group vectors must have same length".into(),
+        ));
+    }
+    if time.is_empty() {
+        return Err(crate::EpiRustError::ComputeError(
+            "cannot run logrank test on empty data".into(),
+        ));
+    }
+
+    let n_groups = group.iter().copied().max().unwrap_or(0) + 1;
+    if n_groups < 2 {
+        return Err(crate::EpiRustError::ComputeError(
+            "logrank test requires at least two groups".into(),
+        ));
+    }
+    let mut label_used = vec![false; n_groups];
+    for &g in group {
+        label_used[g] = true;
+    }
+    if label_used.iter().any(|&used| !used) {
+        return Err(crate::EpiRustError::ComputeError(
+            "group labels must densely cover 0..n_groups with no gaps".into(),
+        ));
+    }
+
+    let mut subjects: Vec<(f64, bool, usize)> = time
+        .iter()
+        .zip(event.iter())
+        .zip(group.iter())
+        .map(|((&t, &e), &g)| (t, e, g))
+        .collect();
+    subjects.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let mut distinct_times: Vec<f64> = subjects.iter().map(|s| s.0).collect();
+    distinct_times.dedup();
+
+    let thread_pool = NumaAwareThreadPool::new(Default::default())?;
+
+    // For each distinct event time, gather n_risk and n_events per group.
+    let per_time: Vec<(Vec<usize>, Vec<usize>)> = thread_pool.run(&distinct_times, |times| {
+        times
+            .par_iter()
+            .map(|&t| {
+                let mut n_risk = vec![0usize; n_groups];
+                let mut n_event = vec![0usize; n_groups];
+                for &(s_t, s_e, s_g) in &subjects {
+                    if s_t >= t {
+                        n_risk[s_g] += 1;
+                    }
+                    if s_t == t && s_e {
+                        n_event[s_g] += 1;
+                    }
+                }
+                (n_risk, n_event)
+            })
+            .collect()
+    });
+
+    // Accumulate O - E per group and the variance-covariance matrix.
+    let mut o_minus_e = vec![0.0; n_groups];
+    let mut variance = vec![vec![0.0; n_groups]; n_groups];
+
+    for (n_risk, n_event) in &per_time {
+        let total_risk: usize = n_risk.iter().sum();
+        let total_events: usize = n_event.iter().sum();
+        if total_risk <= 1 || total_events == 0 {
+            continue;
+        }
+
+        for g in 0..n_groups {
+            let expected = n_risk[g] as f64 * total_events as f64 / total_risk as f64;
+            o_minus_e[g] += n_event[g] as f64 - expected;
+        }
+
+        let factor = total_events as f64 * (total_risk - total_events) as f64
+            / (total_risk as f64 * total_risk as f64 * (total_risk - 1) as f64);
+
+        for gi in 0..n_groups {
+            for gj in 0..n_groups {
+                let indicator = if gi == gj { 1.0 } else { 0.0 };
+                variance[gi][gj] +=
+                    factor * (n_risk[gi] as f64) * (indicator * total_risk as f64 - n_risk[gj] as f64);
+            }
+        }
+    }
+
+    // Use the first `groups - 1` components (variance matrix is singular
+    // with row/column sums of zero, as is standard for this test).
+    let k = n_groups - 1;
+    let o_minus_e_reduced = &o_minus_e[..k];
+    let variance_reduced: Vec<Vec<f64>> = variance[..k].iter().map(|row| row[..k].to_vec()).collect();
+
+    let statistic = quadratic_form(o_minus_e_reduced, &variance_reduced)?;
+    let p_value = 1.0 - chi_square_cdf(statistic, k as f64);
+
+    Ok(LogRankResult {
+        statistic,
+        df: k,
+        p_value,
+    })
+}
+
+/// Solves `x^T V^-1 x` via Gaussian elimination on the augmented system
+/// `V y = x`, then returns `x^T y`.
+fn quadratic_form(x: &[f64], v: &[Vec<f64>]) -> Result<f64, crate::EpiRustError> {
+    let k = x.len();
+    let mut a = v.to_vec();
+    let mut b = x.to_vec();
+
+    for col in 0..k {
+        let pivot_row = (col..k)
+            .max_by(|&r1, &r2| a[r1][col].abs().partial_cmp(&a[r2][col].abs()).unwrap())
+            .unwrap();
+        if a[pivot_row][col].abs() < 1e-12 {
+            continue;
+        }
+        a.swap(col, pivot_row);
+        b.swap(col, pivot_row);
+
+        let pivot = a[col][col];
+        for row in (col + 1)..k {
+            let factor = a[row][col] / pivot;
+            for c in col..k {
+                a[row][c] -= factor * a[col][c];
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut y = vec![0.0; k];
+    for row in (0..k).rev() {
+        if a[row][row].abs() < 1e-12 {
+            continue;
+        }
+        let mut sum = b[row];
+        for c in (row + 1)..k {
+            sum -= a[row][c] * y[c];
+        }
+        y[row] = sum / a[row][row];
+    }
+
+    Ok(x.iter().zip(y.iter()).map(|(&xi, &yi)| xi * yi).sum())
+}
+
+/// Lower-tail chi-square CDF via the regularized lower incomplete gamma
+/// function, evaluated with a series expansion (adequate for the small
+/// integer degrees of freedom a logrank test produces).
+fn chi_square_cdf(x: f64, k: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    regularized_lower_incomplete_gamma(k / 2.0, x / 2.0)
+}
+
+fn regularized_lower_incomplete_gamma(s: f64, x: f64) -> f64 {
+    if x < s + 1.0 {
+        // Series representation.
+        let mut term = 1.0 / s;
+        let mut sum = term;
+        let mut n = 1.0;
+        while term.abs() > 1e-12 * sum.abs() && n < 500.0 {
+            term *= x / (s + n);
+            sum += term;
+            n += 1.0;
+        }
+        sum * (-x + s * x.ln() - ln_gamma(s)).exp()
+    } else {
+        1.0 - regularized_upper_incomplete_gamma_continued_fraction(s, x)
+    }
+}
+
+fn regularized_upper_incomplete_gamma_continued_fraction(s: f64, x: f64) -> f64 {
+    let mut b = x + 1.0 - s;
+    let mut c = 1e300;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..500 {
+        let an = -(i as f64) * (i as f64 - s);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < 1e-300 {
+            d = 1e-300;
+        }
+        c = b + an / c;
+        if c.abs() < 1e-300 {
+            c = 1e-300;
+        }
+        d = 1.0 / d;
+        let del = d * c;
+        h *= del;
+        if (del - 1.0).abs() < 1e-12 {
+            break;
+        }
+    }
+    (-x + s * x.ln() - ln_gamma(s)).exp() * h
+}
+
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+
+    let mut y = x;
+    let tmp = x + 5.5 - (x + 0.5) * (x + 5.5).ln();
+    let mut ser = 1.000000000190015;
+    for &c in COEFFS.iter() {
+        y += 1.0;
+        ser += c / y;
+    }
+    -tmp + (2.5066282746310005 * ser / x).ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_groups_give_small_statistic() {
+        let time = vec![1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0];
+        let event = vec![true, true, true, true, true, true, true, true];
+        let group = vec![0, 0, 0, 0, 1, 1, 1, 1];
+
+        let result = logrank_test(&time, &event, &group).unwrap();
+        assert_eq!(result.df, 1);
+        assert!(result.statistic < 1.0);
+        assert!(result.p_value > 0.5);
+    }
+
+    #[test]
+    fn test_single_group_errors() {
+        let result = logrank_test(&[1.0, 2.0], &[true, true], &[0, 0]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_sparse_group_labels_error() {
+        // Labels 0 and 2 with no 1 would silently imply n_groups = 3 with
+        // an empty middle group and a singular variance-covariance matrix.
+        let time = vec![1.0, 2.0, 3.0, 4.0];
+        let event = vec![true, true, true, true];
+        let group = vec![0, 0, 2, 2];
+
+        let result = logrank_test(&time, &event, &group);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_mismatched_lengths_error() {
+        let result = logrank_test(&[1.0], &[true, false], &[0, 1]);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod kaplan_meier;
+pub mod turnbull;
+pub mod logrank;
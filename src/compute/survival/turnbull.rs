@@ -0,0 +1,362 @@
+use crate::compute::accel::ConvergentSequence;
+use crate::compute::parallel::NumaAwareThreadPool;
+use crate::compute::simd::SimdOperations;
+use rayon::prelude::*;
+
+const DEFAULT_TOLERANCE: f64 = 1e-7;
+const DEFAULT_MAX_ITER: usize = 10_000;
+
+/// Nonparametric MLE of the survival function for interval-censored data,
+/// computed via Turnbull's (1976) self-consistency (EM) algorithm.
+///
+/// Unlike [`KaplanMeier`](super::kaplan_meier::KaplanMeier), which assumes
+/// each subject's event time is observed exactly, `Turnbull` handles
+/// observations recorded only as an interval `[L_i, R_i]` in which the
+/// event is known to have occurred, with `R_i = f64::INFINITY` encoding
+/// right-censoring.
+#[derive(Debug)]
+pub struct Turnbull {
+    simd_ops: SimdOperations,
+    thread_pool: NumaAwareThreadPool,
+}
+
+#[derive(Debug)]
+pub struct TurnbullResult {
+    /// Left boundary of each Turnbull interval on which mass can be placed.
+    pub interval_left: Vec<f64>,
+    /// Right boundary of each Turnbull interval on which mass can be placed.
+    pub interval_right: Vec<f64>,
+    /// NPMLE probability mass assigned to each Turnbull interval.
+    pub mass: Vec<f64>,
+    /// Survival function evaluated just past each interval's right boundary.
+    pub survival: Vec<f64>,
+    /// Cumulative failure probability `1 - S(t)` on the interval grid.
+    pub cumulative_failure: Vec<f64>,
+    /// Number of EM iterations performed before convergence.
+    pub iterations: usize,
+}
+
+impl Turnbull {
+    pub fn new() -> Result<Self, crate::EpiRustError> {
+        Ok(Self {
+            simd_ops: SimdOperations::new(),
+            thread_pool: NumaAwareThreadPool::new(Default::default())?,
+        })
+    }
+
+    /// Fits the NPMLE given left and right interval boundaries for each
+    /// subject. Use `f64::INFINITY` in `right` to denote right-censoring.
+    pub fn fit(&self, left: &[f64], right: &[f64]) -> Result<TurnbullResult, crate::EpiRustError> {
+        if left.len() != right.len() {
+            return Err(crate::EpiRustError::ComputeError(
+                "left and right boundary vectors must have same length".into(),
+            ));
+        }
+        if left.is_empty() {
+            return Err(crate::EpiRustError::ComputeError(
+                "cannot fit Turnbull estimator on empty data".into(),
+            ));
+        }
+        for (&l, &r) in left.iter().zip(right.iter()) {
+            if l > r {
+                return Err(crate::EpiRustError::ComputeError(
+                    "observation left boundary exceeds right boundary".into(),
+                ));
+            }
+        }
+
+        let (mut interval_left, mut interval_right) = Self::turnbull_intervals(left, right);
+        if interval_left.is_empty() {
+            return Err(crate::EpiRustError::ComputeError(
+                "no Turnbull intervals could be formed from the supplied data".into(),
+            ));
+        }
+        Self::ensure_full_coverage(&mut interval_left, &mut interval_right, left, right)?;
+        let m = interval_left.len();
+
+        let alpha = self.build_indicator(left, right, &interval_left, &interval_right);
+
+        let mut s = vec![1.0 / m as f64; m];
+        let n = left.len();
+        let mut iterations = 0;
+        // chunk1-1 re-requested this estimator (it was already built under
+        // chunk0-1); its net-new contribution is `cumulative_failure` below.
+        // Aitken acceleration (chunk0-6) landed in the same commit because it
+        // hooks into this same EM loop.
+        let mut accel = ConvergentSequence::new();
+
+        loop {
+            iterations += 1;
+
+            let denominators: Vec<f64> = self.thread_pool.run(&alpha, |rows| {
+                rows.par_iter()
+                    .map(|row| self.simd_ops.vector_sum(&weighted_row(row, &s)).unwrap_or(0.0))
+                    .collect()
+            });
+
+            let mut next_s = vec![0.0; m];
+            for (row, &d_i) in alpha.iter().zip(denominators.iter()) {
+                if d_i <= 0.0 {
+                    continue;
+                }
+                for (j, &a_ij) in row.iter().enumerate() {
+                    if a_ij {
+                        next_s[j] += s[j] / d_i;
+                    }
+                }
+            }
+            for v in next_s.iter_mut() {
+                *v /= n as f64;
+            }
+
+            // Aitken acceleration can cut the number of self-consistency
+            // passes substantially; fall back to the raw EM step (via
+            // ConvergentSequence's near-zero guard) whenever it would be
+            // unstable, then project back onto the probability simplex.
+            next_s = renormalize(accel.push(&next_s));
+
+            let max_change = s
+                .iter()
+                .zip(next_s.iter())
+                .fold(0.0_f64, |acc, (&old, &new)| acc.max((new - old).abs()));
+
+            s = next_s;
+
+            if max_change < DEFAULT_TOLERANCE || iterations >= DEFAULT_MAX_ITER {
+                break;
+            }
+        }
+
+        // Survival just past interval j is the mass remaining beyond it.
+        let mut survival = vec![0.0; m];
+        let mut remaining = 1.0;
+        for j in 0..m {
+            remaining -= s[j];
+            survival[j] = remaining.max(0.0);
+        }
+        let cumulative_failure = survival.iter().map(|&sv| 1.0 - sv).collect();
+
+        Ok(TurnbullResult {
+            interval_left,
+            interval_right,
+            mass: s,
+            survival,
+            cumulative_failure,
+            iterations,
+        })
+    }
+
+    /// Builds the maximal Turnbull intervals: the regions `[q_j, p_j]`
+    /// formed where a distinct left endpoint is immediately followed, in
+    /// the sorted union of all endpoints, by a distinct right endpoint,
+    /// plus a degenerate `[v, v]` interval wherever `v` is both a left
+    /// and a right endpoint (an exactly-observed event time). Does not
+    /// by itself guarantee every observation is covered; callers should
+    /// follow up with [`ensure_full_coverage`](Self::ensure_full_coverage).
+    fn turnbull_intervals(left: &[f64], right: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        let mut lefts: Vec<f64> = left.to_vec();
+        lefts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        lefts.dedup();
+
+        let mut rights: Vec<f64> = right.iter().copied().filter(|r| r.is_finite()).collect();
+        rights.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        rights.dedup();
+
+        let mut values: Vec<f64> = lefts.iter().chain(rights.iter()).copied().collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        values.dedup();
+
+        let is_left = |v: f64| lefts.binary_search_by(|x| x.partial_cmp(&v).unwrap()).is_ok();
+        let is_right = |v: f64| rights.binary_search_by(|x| x.partial_cmp(&v).unwrap()).is_ok();
+
+        let mut intervals: Vec<(f64, f64)> = Vec::new();
+
+        for &v in &values {
+            if is_left(v) && is_right(v) {
+                intervals.push((v, v));
+            }
+        }
+        for w in 0..values.len().saturating_sub(1) {
+            if is_left(values[w]) && is_right(values[w + 1]) {
+                intervals.push((values[w], values[w + 1]));
+            }
+        }
+
+        intervals.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        (
+            intervals.iter().map(|&(q, _)| q).collect(),
+            intervals.iter().map(|&(_, p)| p).collect(),
+        )
+    }
+
+    /// Ensures every observation interval `[l_i, r_i]` fully contains at
+    /// least one constructed Turnbull interval (otherwise its EM row is
+    /// all-`false` and its likelihood contribution is silently dropped).
+    /// Right-censored subjects (`r_i = inf`) past the last finite
+    /// endpoint legitimately need a tail interval reaching to infinity,
+    /// which is appended here; any other uncovered subject indicates the
+    /// construction missed a case, which is reported as an error rather
+    /// than silently losing that subject's mass.
+    fn ensure_full_coverage(
+        interval_left: &mut Vec<f64>,
+        interval_right: &mut Vec<f64>,
+        left: &[f64],
+        right: &[f64],
+    ) -> Result<(), crate::EpiRustError> {
+        let mut tail_start: Option<f64> = None;
+
+        for (&l_i, &r_i) in left.iter().zip(right.iter()) {
+            if covers_observation(interval_left, interval_right, l_i, r_i) {
+                continue;
+            }
+            if r_i.is_infinite() {
+                tail_start = Some(tail_start.map_or(l_i, |t| t.max(l_i)));
+            } else {
+                return Err(crate::EpiRustError::ComputeError(
+                    "observation interval is not covered by any constructed Turnbull interval".into(),
+                ));
+            }
+        }
+
+        if let Some(start) = tail_start {
+            let mut combined: Vec<(f64, f64)> = interval_left
+                .iter()
+                .zip(interval_right.iter())
+                .map(|(&q, &p)| (q, p))
+                .collect();
+            combined.push((start, f64::INFINITY));
+            combined.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+            *interval_left = combined.iter().map(|&(q, _)| q).collect();
+            *interval_right = combined.iter().map(|&(_, p)| p).collect();
+        }
+
+        Ok(())
+    }
+
+    /// `alpha[i][j] = true` when Turnbull interval `j` lies within
+    /// observation interval `i`.
+    fn build_indicator(
+        &self,
+        left: &[f64],
+        right: &[f64],
+        interval_left: &[f64],
+        interval_right: &[f64],
+    ) -> Vec<Vec<bool>> {
+        left.iter()
+            .zip(right.iter())
+            .map(|(&l_i, &r_i)| {
+                interval_left
+                    .iter()
+                    .zip(interval_right.iter())
+                    .map(|(&q_j, &p_j)| l_i <= q_j && p_j <= r_i)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Whether some constructed Turnbull interval `[q, p]` lies fully inside
+/// the observation interval `[l_i, r_i]` (the same containment rule
+/// `build_indicator` uses to populate `alpha`).
+fn covers_observation(interval_left: &[f64], interval_right: &[f64], l_i: f64, r_i: f64) -> bool {
+    interval_left
+        .iter()
+        .zip(interval_right.iter())
+        .any(|(&q, &p)| l_i <= q && p <= r_i)
+}
+
+fn weighted_row(row: &[bool], s: &[f64]) -> Vec<f64> {
+    row.iter()
+        .zip(s.iter())
+        .map(|(&a, &s_j)| if a { s_j } else { 0.0 })
+        .collect()
+}
+
+/// Clamps negative masses (possible after Aitken extrapolation) to zero
+/// and rescales so the probability mass sums back to one.
+fn renormalize(mut s: Vec<f64>) -> Vec<f64> {
+    for v in s.iter_mut() {
+        if *v < 0.0 {
+            *v = 0.0;
+        }
+    }
+
+    let total: f64 = s.iter().sum();
+    if total > 0.0 {
+        for v in s.iter_mut() {
+            *v /= total;
+        }
+    }
+
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turnbull_right_censored_reduces_to_kaplan_meier_shape() {
+        let left = vec![1.0, 2.0, 3.0, 4.0];
+        let right = vec![1.0, f64::INFINITY, 3.0, f64::INFINITY];
+
+        let tb = Turnbull::new().unwrap();
+        let result = tb.fit(&left, &right).unwrap();
+
+        assert!(result.survival.iter().all(|&s| (0.0..=1.0).contains(&s)));
+        for i in 1..result.survival.len() {
+            assert!(result.survival[i] <= result.survival[i - 1] + 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_turnbull_rejects_inverted_interval() {
+        let tb = Turnbull::new().unwrap();
+        let result = tb.fit(&[5.0], &[1.0]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_turnbull_cumulative_failure_is_complement_of_survival() {
+        let left = vec![1.0, 2.0, 3.0, 4.0];
+        let right = vec![1.0, f64::INFINITY, 3.0, f64::INFINITY];
+
+        let tb = Turnbull::new().unwrap();
+        let result = tb.fit(&left, &right).unwrap();
+
+        for (s, f) in result.survival.iter().zip(result.cumulative_failure.iter()) {
+            assert!((s + f - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_turnbull_mass_sums_to_one() {
+        let left = vec![0.0, 1.0, 2.0, 0.0];
+        let right = vec![2.0, 3.0, 4.0, 1.0];
+
+        let tb = Turnbull::new().unwrap();
+        let result = tb.fit(&left, &right).unwrap();
+
+        let total: f64 = result.mass.iter().sum();
+        assert!((total - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_turnbull_covers_exact_and_trailing_censored_subjects() {
+        // Subjects 0 and 2 are exactly observed (L == R); subject 3 is
+        // right-censored past every finite endpoint in the data. None of
+        // these used to get a Turnbull interval contained in their
+        // observation window, so their EM row was all-false and their
+        // mass was silently dropped.
+        let left = vec![1.0, 2.0, 3.0, 4.0];
+        let right = vec![1.0, f64::INFINITY, 3.0, f64::INFINITY];
+
+        let tb = Turnbull::new().unwrap();
+        let result = tb.fit(&left, &right).unwrap();
+
+        let total: f64 = result.mass.iter().sum();
+        assert!((total - 1.0).abs() < 1e-6);
+    }
+}
@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 pub mod datasets;
 pub mod compute;
 pub mod stats;
+pub mod bindings;
 mod simd;
 mod parallel;
 mod memory;
@@ -15,6 +16,7 @@ fn epirust(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     simd::init_submodule(py, m)?;
     parallel::init_submodule(py, m)?;
     memory::init_submodule(py, m)?;
+    bindings::python::register_survival_module(py, m)?;
 
     Ok(())
 }
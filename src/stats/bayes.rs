@@ -0,0 +1,217 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rand_distr::{Beta as BetaSampler, Distribution, Gamma as GammaSampler};
+use statrs::distribution::{Beta as BetaDist, ContinuousCDF, Gamma as GammaDist};
+
+/// Conjugate Beta posterior for a binomial exposure risk, updated from a
+/// `Beta(alpha, beta)` prior as `Beta(alpha + cases, beta + noncases)`.
+#[derive(Debug, Clone, Copy)]
+pub struct BetaPosterior {
+    pub alpha: f64,
+    pub beta: f64,
+}
+
+impl BetaPosterior {
+    pub fn from_binomial(prior_alpha: f64, prior_beta: f64, cases: f64, noncases: f64) -> Self {
+        Self {
+            alpha: prior_alpha + cases,
+            beta: prior_beta + noncases,
+        }
+    }
+
+    pub fn posterior_mean(&self) -> f64 {
+        self.alpha / (self.alpha + self.beta)
+    }
+
+    pub fn credible_interval(&self, level: f64) -> Result<(f64, f64), crate::EpiRustError> {
+        let (lower_tail, upper_tail) = tail_probabilities(level)?;
+        let dist = BetaDist::new(self.alpha, self.beta)
+            .map_err(|e| crate::EpiRustError::ComputeError(e.to_string()))?;
+        Ok((dist.inverse_cdf(lower_tail), dist.inverse_cdf(upper_tail)))
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> Result<f64, crate::EpiRustError> {
+        let sampler = BetaSampler::new(self.alpha, self.beta)
+            .map_err(|e| crate::EpiRustError::ComputeError(e.to_string()))?;
+        Ok(sampler.sample(rng))
+    }
+}
+
+/// Conjugate Gamma posterior for a Poisson incidence rate, updated from
+/// a `Gamma(alpha, beta)` prior (shape/rate parameterization) as
+/// `Gamma(alpha + events, beta + person_time)`.
+#[derive(Debug, Clone, Copy)]
+pub struct GammaPosterior {
+    pub shape: f64,
+    pub rate: f64,
+}
+
+impl GammaPosterior {
+    pub fn from_poisson(prior_shape: f64, prior_rate: f64, events: f64, person_time: f64) -> Self {
+        Self {
+            shape: prior_shape + events,
+            rate: prior_rate + person_time,
+        }
+    }
+
+    pub fn posterior_mean(&self) -> f64 {
+        self.shape / self.rate
+    }
+
+    pub fn credible_interval(&self, level: f64) -> Result<(f64, f64), crate::EpiRustError> {
+        let (lower_tail, upper_tail) = tail_probabilities(level)?;
+        let dist = GammaDist::new(self.shape, self.rate)
+            .map_err(|e| crate::EpiRustError::ComputeError(e.to_string()))?;
+        Ok((dist.inverse_cdf(lower_tail), dist.inverse_cdf(upper_tail)))
+    }
+
+    fn sample(&self, rng: &mut StdRng) -> Result<f64, crate::EpiRustError> {
+        let sampler = GammaSampler::new(self.shape, 1.0 / self.rate)
+            .map_err(|e| crate::EpiRustError::ComputeError(e.to_string()))?;
+        Ok(sampler.sample(rng))
+    }
+}
+
+/// Beta-Binomial posteriors for exposed and unexposed arms, used to
+/// build a credible interval for the risk ratio and risk difference
+/// rather than relying on a bare point estimate.
+#[derive(Debug, Clone, Copy)]
+pub struct RiskPosterior {
+    pub exposed: BetaPosterior,
+    pub unexposed: BetaPosterior,
+}
+
+impl RiskPosterior {
+    pub fn from_counts(
+        exposed_cases: f64,
+        exposed_noncases: f64,
+        unexposed_cases: f64,
+        unexposed_noncases: f64,
+        prior_alpha: f64,
+        prior_beta: f64,
+    ) -> Self {
+        Self {
+            exposed: BetaPosterior::from_binomial(prior_alpha, prior_beta, exposed_cases, exposed_noncases),
+            unexposed: BetaPosterior::from_binomial(prior_alpha, prior_beta, unexposed_cases, unexposed_noncases),
+        }
+    }
+
+    /// Draws `n` Monte Carlo samples from both arms' posteriors and
+    /// returns the resulting risk-ratio posterior samples.
+    pub fn posterior_ratio_samples(&self, n: usize, seed: u64) -> Result<Vec<f64>, crate::EpiRustError> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..n)
+            .map(|_| Ok(self.exposed.sample(&mut rng)? / self.unexposed.sample(&mut rng)?))
+            .collect()
+    }
+
+    /// Draws `n` Monte Carlo samples of the risk difference
+    /// (`p_exposed - p_unexposed`).
+    pub fn posterior_difference_samples(&self, n: usize, seed: u64) -> Result<Vec<f64>, crate::EpiRustError> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..n)
+            .map(|_| Ok(self.exposed.sample(&mut rng)? - self.unexposed.sample(&mut rng)?))
+            .collect()
+    }
+}
+
+/// Gamma-Poisson posteriors for exposed and unexposed person-time, used
+/// to build a credible interval for the incidence rate ratio.
+#[derive(Debug, Clone, Copy)]
+pub struct IncidenceRatePosterior {
+    pub exposed: GammaPosterior,
+    pub unexposed: GammaPosterior,
+}
+
+impl IncidenceRatePosterior {
+    pub fn from_counts(
+        exposed_events: f64,
+        exposed_person_time: f64,
+        unexposed_events: f64,
+        unexposed_person_time: f64,
+        prior_shape: f64,
+        prior_rate: f64,
+    ) -> Self {
+        Self {
+            exposed: GammaPosterior::from_poisson(prior_shape, prior_rate, exposed_events, exposed_person_time),
+            unexposed: GammaPosterior::from_poisson(prior_shape, prior_rate, unexposed_events, unexposed_person_time),
+        }
+    }
+
+    /// Draws `n` Monte Carlo samples of the incidence rate ratio.
+    pub fn posterior_ratio_samples(&self, n: usize, seed: u64) -> Result<Vec<f64>, crate::EpiRustError> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..n)
+            .map(|_| Ok(self.exposed.sample(&mut rng)? / self.unexposed.sample(&mut rng)?))
+            .collect()
+    }
+}
+
+/// Converts a two-sided credible level (e.g. `0.95`) to the lower and
+/// upper tail probabilities used to query a distribution's inverse CDF.
+fn tail_probabilities(level: f64) -> Result<(f64, f64), crate::EpiRustError> {
+    if !(0.0 < level && level < 1.0) {
+        return Err(crate::EpiRustError::ComputeError(
+            "credible level must be in (0, 1)".into(),
+        ));
+    }
+    let tail = (1.0 - level) / 2.0;
+    Ok((tail, 1.0 - tail))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_beta_posterior_mean_matches_observed_rate() {
+        let posterior = BetaPosterior::from_binomial(1.0, 1.0, 30.0, 70.0);
+        assert!((posterior.posterior_mean() - (31.0 / 102.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_beta_credible_interval_brackets_mean() {
+        let posterior = BetaPosterior::from_binomial(1.0, 1.0, 30.0, 70.0);
+        let (lower, upper) = posterior.credible_interval(0.95).unwrap();
+        let mean = posterior.posterior_mean();
+        assert!(lower < mean && mean < upper);
+    }
+
+    #[test]
+    fn test_gamma_posterior_mean_matches_rate() {
+        let posterior = GammaPosterior::from_poisson(1.0, 1.0, 20.0, 100.0);
+        assert!((posterior.posterior_mean() - (21.0 / 101.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_risk_posterior_ratio_samples_center_near_point_estimate() {
+        let posterior = RiskPosterior::from_counts(40.0, 60.0, 20.0, 80.0, 1.0, 1.0);
+        let samples = posterior.posterior_ratio_samples(20_000, 123).unwrap();
+        let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
+
+        let point_estimate = (41.0 / 102.0) / (21.0 / 102.0);
+        assert!((mean - point_estimate).abs() / point_estimate < 0.1);
+    }
+
+    #[test]
+    fn test_incidence_rate_ratio_samples_are_positive() {
+        let posterior = IncidenceRatePosterior::from_counts(15.0, 500.0, 5.0, 500.0, 1.0, 1.0);
+        let samples = posterior.posterior_ratio_samples(1000, 7).unwrap();
+        assert!(samples.iter().all(|&v| v > 0.0));
+    }
+
+    #[test]
+    fn test_risk_posterior_ratio_samples_reject_non_positive_posterior() {
+        // A zero-count arm with an improper (non-positive) prior yields a
+        // non-positive alpha/beta, which used to panic inside `sample`.
+        let posterior = RiskPosterior::from_counts(0.0, 0.0, 20.0, 80.0, -1.0, -1.0);
+        assert!(posterior.posterior_ratio_samples(10, 1).is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_credible_level() {
+        let posterior = BetaPosterior::from_binomial(1.0, 1.0, 5.0, 5.0);
+        assert!(posterior.credible_interval(0.0).is_err());
+        assert!(posterior.credible_interval(1.0).is_err());
+    }
+}
@@ -1,6 +1,9 @@
 use pyo3::prelude::*;
 use statrs::distribution::{ContinuousCDF, Normal};
 
+pub mod quantile;
+pub mod bayes;
+
 /// Statistical analysis module
 pub fn init_submodule(py: Python<'_>, parent_module: &PyModule) -> PyResult<()> {
     let submod = PyModule::new(py, "stats")?;
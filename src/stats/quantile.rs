@@ -0,0 +1,76 @@
+use crate::compute::quantile::EpsilonSummary;
+use rayon::prelude::*;
+
+/// Streaming epsilon-approximate quantile summary for follow-up times,
+/// biomarker distributions, or other cohort-level measurements too large
+/// to sort or hold in memory in full. This is the same Zhang-Wang
+/// summary that backs `compute::quantile::EpsilonSummary`; it's
+/// re-exported under `stats` so statistical analyses can reach it
+/// directly, and the `insert`/`merge`/`query` API serves both the
+/// fixed-`N` case (ingest a known-size sample) and the unbounded
+/// streaming case (ingest indefinitely, compressing as you go).
+pub type StreamingQuantileSummary = EpsilonSummary;
+
+/// Builds one summary per chunk of `data` in parallel (mirroring the
+/// per-thread workers in the `parallel` module), then merges the partial
+/// summaries into a single one preserving the `epsilon` guarantee.
+pub fn parallel_summarize(
+    data: &[f64],
+    epsilon: f64,
+    chunk_size: usize,
+) -> Result<EpsilonSummary, crate::EpiRustError> {
+    if chunk_size == 0 {
+        return Err(crate::EpiRustError::ComputeError(
+            "chunk_size must be greater than zero".into(),
+        ));
+    }
+    let mut merged = EpsilonSummary::new(epsilon)?;
+
+    let partials: Vec<EpsilonSummary> = data
+        .par_chunks(chunk_size)
+        .map(|chunk| {
+            let mut summary = EpsilonSummary::new(epsilon).expect("epsilon already validated by caller");
+            for &value in chunk {
+                summary.insert(value);
+            }
+            summary
+        })
+        .collect();
+
+    for partial in &partials {
+        merged.merge(partial);
+    }
+
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parallel_summarize_matches_single_threaded() {
+        let data: Vec<f64> = (1..=1000).map(|v| v as f64).collect();
+
+        let parallel = parallel_summarize(&data, 0.01, 100).unwrap();
+
+        let mut sequential = EpsilonSummary::new(0.01).unwrap();
+        for &v in &data {
+            sequential.insert(v);
+        }
+
+        let parallel_median = parallel.query(0.5).unwrap();
+        let sequential_median = sequential.query(0.5).unwrap();
+        // With the real hierarchical block-merge, the combined summary
+        // should honor approximately its nominal epsilon * n bound
+        // (epsilon=0.01, n=1000) rather than the much looser slack the
+        // value-replay merge used to need.
+        assert!((parallel_median - sequential_median).abs() <= 10.0);
+    }
+
+    #[test]
+    fn test_rejects_zero_chunk_size() {
+        let result = parallel_summarize(&[1.0, 2.0], 0.1, 0);
+        assert!(result.is_err());
+    }
+}